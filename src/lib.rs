@@ -1,129 +1,601 @@
-use std::hash::{Hash, Hasher};
-use std::collections::hash_map::DefaultHasher;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
+use std::iter::FusedIterator;
 use std::option::Option::Some;
-use std::fmt::Display;
 
 #[derive(Debug)]
-pub struct HashTable<K, V> where K: Eq + Hash + Display {
-    buckets: Vec<Bucket<K, V>>,
-}
-
-#[derive(Debug)]
-struct Bucket<K, V> where K: Eq + Hash + Display {
-    head: Link<K, V>,
+pub struct HashTable<K, V, S = RandomState> where K: Eq + Hash {
+    slots: Vec<Option<Slot<K, V>>>,
     len: usize,
+    hash_builder: S,
 }
 
-type Link<K, V> = Option<Box<Node<K, V>>>;
-
 #[derive(Debug)]
-struct Node<K, V> where K: Eq + Hash + Display {
+struct Slot<K, V> where K: Eq + Hash {
     key: K,
     value: V,
-    next: Link<K, V>,
+    hash: u64,
+}
+
+/// Why [`HashTable::try_reserve`] could not grow the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The requested capacity would overflow `usize`, or its size in bytes
+    /// would exceed `isize::MAX`.
+    CapacityOverflow,
+    /// The allocator reported an allocation failure.
+    AllocError,
+}
+
+impl std::fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryReserveError::CapacityOverflow => write!(f, "capacity overflow"),
+            TryReserveError::AllocError => write!(f, "memory allocation failed"),
+        }
+    }
+}
+
+impl std::error::Error for TryReserveError {}
+
+impl From<std::collections::TryReserveError> for TryReserveError {
+    fn from(_: std::collections::TryReserveError) -> Self {
+        TryReserveError::AllocError
+    }
 }
 
-const BUCKET_SIZE: usize = 8;
+const INITIAL_CAPACITY: usize = 8;
+const GROWTH_FACTOR: usize = 2;
+const LOAD_FACTOR: f64 = 0.75;
 
-impl<K, V> HashTable<K, V> where K: Eq + Hash + Display {
+impl<K, V> HashTable<K, V, RandomState> where K: Eq + Hash {
     pub fn new() -> Self {
-        let mut buckets = Vec::with_capacity(BUCKET_SIZE);
+        Self::with_hasher(RandomState::new())
+    }
+
+    pub fn with_capacity(n: usize) -> Self {
+        Self::with_capacity_and_hasher(n, RandomState::new())
+    }
+}
 
-        for i in 0..BUCKET_SIZE {
-            buckets.insert(i, Bucket { head: None, len: 0 })
+impl<K, V> Default for HashTable<K, V, RandomState> where K: Eq + Hash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, S> HashTable<K, V, S> where K: Eq + Hash, S: BuildHasher {
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher(INITIAL_CAPACITY, hash_builder)
+    }
+
+    pub fn with_capacity_and_hasher(n: usize, hash_builder: S) -> Self {
+        let capacity = n.max(1).next_power_of_two();
+        HashTable {
+            slots: Self::new_slots(capacity),
+            len: 0,
+            hash_builder,
         }
+    }
 
-        HashTable { buckets }
+    fn new_slots(capacity: usize) -> Vec<Option<Slot<K, V>>> {
+        let mut slots = Vec::with_capacity(capacity);
+        for _ in 0..capacity {
+            slots.push(None);
+        }
+        slots
     }
 
     pub fn len(&self) -> usize {
-        self.buckets.iter().map(|bucket| bucket.len).sum()
+        self.len
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
     }
 
     pub fn insert(&mut self, key: K, value: V) {
-        let index = Self::hash(&key);
-        self.buckets[index].insert(key, value);
+        self.grow_if_needed();
+
+        let hash = self.hash(&key);
+        let (is_new, _) = self.insert_slot(Slot { key, value, hash });
+        if is_new {
+            self.len += 1;
+        }
+    }
+
+    /// Gets the given key's corresponding entry for in-place insert-or-update,
+    /// hashing the key once. An occupied entry holds the slot index found by
+    /// that lookup; a vacant entry holds only the key and its hash, and
+    /// inserting into it may grow the table and probes again from that hash.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        let hash = self.hash(&key);
+        match self.find_slot_with_hash(&key, hash) {
+            Some(index) => Entry::Occupied(OccupiedEntry { table: self, index }),
+            None => Entry::Vacant(VacantEntry { table: self, key, hash }),
+        }
     }
 
     pub fn remove(&mut self, key: &K) {
-        let index = Self::hash(key);
-        self.buckets[index].remove(key);
+        let Some(mut index) = self.find_slot(key) else { return };
+        self.slots[index] = None;
+        self.len -= 1;
+
+        // Backward-shift deletion: pull the trailing chain back one slot at a
+        // time until we hit a gap or an entry that is already at its ideal
+        // position, so later lookups don't see a premature `None`.
+        let capacity = self.slots.len();
+        loop {
+            let next = (index + 1) & (capacity - 1);
+            match &self.slots[next] {
+                None => break,
+                Some(slot) if Self::ideal_index(slot.hash, capacity) == next => break,
+                Some(_) => {
+                    self.slots[index] = self.slots[next].take();
+                    index = next;
+                }
+            }
+        }
     }
 
     pub fn get(&self, key: &K) -> Option<&V> {
-        let index = Self::hash(key);
-        self.buckets[index].get(key)
+        let index = self.find_slot(key)?;
+        self.slots[index].as_ref().map(|slot| &slot.value)
     }
 
-    fn hash(key: &K) -> usize {
-        let mut hasher = DefaultHasher::new();
-        key.hash(&mut hasher);
-        let index = hasher.finish() % BUCKET_SIZE as u64;
-        index as usize
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { slots: self.slots.iter(), remaining: self.len }
     }
-}
 
-impl<K, V> Bucket<K, V> where K: Hash + Eq + Display {
-    pub fn insert(&mut self, key: K, value: V) {
-        let mut current = &mut self.head;
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut { slots: self.slots.iter_mut(), remaining: self.len }
+    }
+
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { inner: self.iter() }
+    }
+
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { inner: self.iter() }
+    }
+
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut { inner: self.iter_mut() }
+    }
+
+    /// Probes forward from `key`'s ideal index and returns the index of its
+    /// slot, stopping early once the search has gone farther than any
+    /// resident could have travelled (Robin Hood's early-exit guarantee).
+    fn find_slot(&self, key: &K) -> Option<usize> {
+        let hash = self.hash(key);
+        self.find_slot_with_hash(key, hash)
+    }
+
+    fn find_slot_with_hash(&self, key: &K, hash: u64) -> Option<usize> {
+        let capacity = self.slots.len();
+        let mut index = Self::ideal_index(hash, capacity);
+        let mut distance = 0;
 
         loop {
-            match current {
-                None => break,
-                Some(node) if node.key == key => {
-                    break;
-                }
-                Some(node) => {
-                    current = &mut node.next;
+            match &self.slots[index] {
+                None => return None,
+                Some(slot) => {
+                    if slot.hash == hash && slot.key == *key {
+                        return Some(index);
+                    }
+                    if distance > Self::probe_distance(index, slot.hash, capacity) {
+                        return None;
+                    }
                 }
             }
-        };
-
-        if let Some(node) = current {
-            node.value = value
-        } else {
-            let head = self.head.take();
-            self.head = Some(Box::new(Node{ key, value, next: head}));
-            self.len += 1;
+            index = (index + 1) & (capacity - 1);
+            distance += 1;
         }
     }
 
-    pub fn remove(&mut self, key: &K) {
-        let mut current = &mut self.head;
+    /// Inserts `slot`, Robin-Hood-swapping it with any resident it has
+    /// travelled farther than. Returns whether a new entry was added, and the
+    /// index the passed-in key ended up at (its first placement, since once
+    /// written there nothing displaces it further).
+    fn insert_slot(&mut self, mut slot: Slot<K, V>) -> (bool, usize) {
+        let capacity = self.slots.len();
+        let mut index = Self::ideal_index(slot.hash, capacity);
+        let mut distance = 0;
+        let mut landed = None;
+
         loop {
-            match current {
-                None => return,
-                Some(node) if &node.key == key => {
-                    *current = node.next.take();
-                    self.len -= 1;
-                    return;
+            match self.slots[index].take() {
+                None => {
+                    self.slots[index] = Some(slot);
+                    return (true, landed.unwrap_or(index));
+                }
+                Some(resident) if resident.hash == slot.hash && resident.key == slot.key => {
+                    self.slots[index] = Some(Slot { value: slot.value, ..resident });
+                    return (false, index);
                 }
-                Some(node) => {
-                    current = &mut node.next;
+                Some(resident) => {
+                    let resident_distance = Self::probe_distance(index, resident.hash, capacity);
+                    if distance > resident_distance {
+                        self.slots[index] = Some(slot);
+                        landed.get_or_insert(index);
+                        slot = resident;
+                        distance = resident_distance;
+                    } else {
+                        self.slots[index] = Some(resident);
+                    }
                 }
             }
+            index = (index + 1) & (capacity - 1);
+            distance += 1;
         }
     }
 
-    pub fn get(&self, key: &K) -> Option<&V> {
-        let mut current = &self.head;
-        loop {
-            match current {
-                None => return None,
-                Some(node) if &node.key == key => {
-                    return Some(&node.value);
-                }
-                Some(node) => {
-                    current = &node.next;
-                }
+    fn grow_if_needed(&mut self) {
+        if (self.len + 1) as f64 > self.slots.len() as f64 * LOAD_FACTOR {
+            self.resize(self.slots.len() * GROWTH_FACTOR);
+        }
+    }
+
+    fn resize(&mut self, new_capacity: usize) {
+        self.try_resize(new_capacity)
+            .expect("HashTable: capacity overflow or allocation failure while growing");
+    }
+
+    /// Ensures the table can hold `additional` more elements than its current
+    /// length without reallocating, growing the bucket vector up front.
+    pub fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional)
+            .expect("HashTable::reserve: capacity overflow or allocation failure");
+    }
+
+    /// Fallible counterpart to [`HashTable::reserve`]: reports capacity
+    /// overflow or allocator failure instead of panicking.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self.len.checked_add(additional).ok_or(TryReserveError::CapacityOverflow)?;
+        let min_capacity = Self::capacity_for(required)?;
+
+        if min_capacity <= self.slots.len() {
+            return Ok(());
+        }
+        self.try_resize(min_capacity)
+    }
+
+    fn try_resize(&mut self, new_capacity: usize) -> Result<(), TryReserveError> {
+        std::alloc::Layout::array::<Option<Slot<K, V>>>(new_capacity)
+            .map_err(|_| TryReserveError::CapacityOverflow)?;
+        let mut new_slots = Vec::new();
+        new_slots.try_reserve_exact(new_capacity).map_err(TryReserveError::from)?;
+        new_slots.resize_with(new_capacity, || None);
+
+        let old_slots = std::mem::replace(&mut self.slots, new_slots);
+        for slot in old_slots.into_iter().flatten() {
+            self.insert_slot(slot);
+        }
+        Ok(())
+    }
+
+    /// Smallest power-of-two capacity whose load factor bound can hold `required` elements.
+    fn capacity_for(required: usize) -> Result<usize, TryReserveError> {
+        let needed = (required as f64 / LOAD_FACTOR).ceil();
+        if needed > usize::MAX as f64 {
+            return Err(TryReserveError::CapacityOverflow);
+        }
+        (needed as usize)
+            .max(1)
+            .checked_next_power_of_two()
+            .ok_or(TryReserveError::CapacityOverflow)
+    }
+
+    fn slot_value_mut(&mut self, index: usize) -> &mut V {
+        &mut self.slots[index].as_mut().expect("entry index must be occupied").value
+    }
+
+    fn hash(&self, key: &K) -> u64 {
+        self.hash_builder.hash_one(key)
+    }
+
+    fn ideal_index(hash: u64, capacity: usize) -> usize {
+        hash as usize & (capacity - 1)
+    }
+
+    /// How many slots past its ideal index a resident at `index` has travelled.
+    /// Capacity is a power of two, so the mask is equivalent to (and cheaper
+    /// than) a modulo that accounts for wraparound.
+    fn probe_distance(index: usize, hash: u64, capacity: usize) -> usize {
+        index.wrapping_sub(Self::ideal_index(hash, capacity)) & (capacity - 1)
+    }
+}
+
+/// A view into a single entry in a `HashTable`, obtained from [`HashTable::entry`].
+pub enum Entry<'a, K, V, S> where K: Eq + Hash {
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+impl<'a, K, V, S> Entry<'a, K, V, S> where K: Eq + Hash, S: BuildHasher {
+    /// Ensures a value is present, inserting `default` if the entry is vacant,
+    /// then returns a mutable reference to it.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Like [`Entry::or_insert`], but computes the default lazily, only on a vacant entry.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Applies `f` to the value if the entry is occupied, then returns the entry unchanged.
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
             }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
         }
     }
 }
 
+/// An occupied entry, resolved to its slot index by a single lookup in `HashTable::entry`.
+pub struct OccupiedEntry<'a, K, V, S> where K: Eq + Hash {
+    table: &'a mut HashTable<K, V, S>,
+    index: usize,
+}
+
+impl<'a, K, V, S> OccupiedEntry<'a, K, V, S> where K: Eq + Hash, S: BuildHasher {
+    pub fn key(&self) -> &K {
+        &self.table.slots[self.index].as_ref().expect("entry index must be occupied").key
+    }
+
+    pub fn get(&self) -> &V {
+        &self.table.slots[self.index].as_ref().expect("entry index must be occupied").value
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        self.table.slot_value_mut(self.index)
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        self.table.slot_value_mut(self.index)
+    }
+
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+}
+
+/// A vacant entry, holding the key and its already-computed hash so that
+/// inserting it doesn't need to hash it again.
+pub struct VacantEntry<'a, K, V, S> where K: Eq + Hash {
+    table: &'a mut HashTable<K, V, S>,
+    key: K,
+    hash: u64,
+}
+
+impl<'a, K, V, S> VacantEntry<'a, K, V, S> where K: Eq + Hash, S: BuildHasher {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.table.grow_if_needed();
+        let (_, index) = self.table.insert_slot(Slot { key: self.key, value, hash: self.hash });
+        self.table.len += 1;
+        self.table.slot_value_mut(index)
+    }
+}
+
+/// An iterator over `(&K, &V)` pairs of a `HashTable`, created by [`HashTable::iter`].
+pub struct Iter<'a, K, V> where K: Eq + Hash {
+    slots: std::slice::Iter<'a, Option<Slot<K, V>>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> where K: Eq + Hash {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slot = self.slots.by_ref().flatten().next()?;
+        self.remaining -= 1;
+        Some((&slot.key, &slot.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> where K: Eq + Hash {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> where K: Eq + Hash {}
+
+/// An iterator over `(&K, &mut V)` pairs of a `HashTable`, created by [`HashTable::iter_mut`].
+pub struct IterMut<'a, K, V> where K: Eq + Hash {
+    slots: std::slice::IterMut<'a, Option<Slot<K, V>>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> where K: Eq + Hash {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slot = self.slots.by_ref().flatten().next()?;
+        self.remaining -= 1;
+        Some((&slot.key, &mut slot.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> where K: Eq + Hash {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> where K: Eq + Hash {}
+
+/// An owning iterator over `(K, V)` pairs of a `HashTable`, created by its `IntoIterator` impl.
+pub struct IntoIter<K, V> where K: Eq + Hash {
+    slots: std::vec::IntoIter<Option<Slot<K, V>>>,
+    remaining: usize,
+}
+
+impl<K, V> Iterator for IntoIter<K, V> where K: Eq + Hash {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slot = self.slots.by_ref().flatten().next()?;
+        self.remaining -= 1;
+        Some((slot.key, slot.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> where K: Eq + Hash {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<K, V> FusedIterator for IntoIter<K, V> where K: Eq + Hash {}
+
+/// An iterator over the keys of a `HashTable`, created by [`HashTable::keys`].
+pub struct Keys<'a, K, V> where K: Eq + Hash {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> where K: Eq + Hash {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(k, _)| k)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> where K: Eq + Hash {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<K, V> FusedIterator for Keys<'_, K, V> where K: Eq + Hash {}
+
+/// An iterator over the values of a `HashTable`, created by [`HashTable::values`].
+pub struct Values<'a, K, V> where K: Eq + Hash {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> where K: Eq + Hash {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> where K: Eq + Hash {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<K, V> FusedIterator for Values<'_, K, V> where K: Eq + Hash {}
+
+/// An iterator over mutable references to the values of a `HashTable`, created by [`HashTable::values_mut`].
+pub struct ValuesMut<'a, K, V> where K: Eq + Hash {
+    inner: IterMut<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> where K: Eq + Hash {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for ValuesMut<'_, K, V> where K: Eq + Hash {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<K, V> FusedIterator for ValuesMut<'_, K, V> where K: Eq + Hash {}
+
+impl<K, V, S> IntoIterator for HashTable<K, V, S> where K: Eq + Hash {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { slots: self.slots.into_iter(), remaining: self.len }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a HashTable<K, V, S> where K: Eq + Hash, S: BuildHasher {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut HashTable<K, V, S> where K: Eq + Hash, S: BuildHasher {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for HashTable<K, V, S> where K: Eq + Hash, S: BuildHasher {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for HashTable<K, V, RandomState> where K: Eq + Hash {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut table = HashTable::new();
+        table.extend(iter);
+        table
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::HashTable;
+    use crate::{HashTable, TryReserveError};
 
     #[test]
     fn it_works() {
@@ -143,4 +615,157 @@ mod tests {
         assert_eq!(None, hashtable.get(&"Lion".to_string()));
         assert_eq!(3, hashtable.len());
     }
+
+    #[test]
+    fn grows_and_rehashes_as_it_fills() {
+        let mut hashtable = HashTable::with_capacity(4);
+
+        for i in 0..100 {
+            hashtable.insert(i, i * 2);
+        }
+
+        assert_eq!(100, hashtable.len());
+        assert!(hashtable.capacity() > 4);
+
+        for i in 0..100 {
+            assert_eq!(Some(&(i * 2)), hashtable.get(&i));
+        }
+    }
+
+    #[test]
+    fn remove_preserves_probe_chains() {
+        let mut hashtable = HashTable::with_capacity(8);
+
+        for i in 0..6 {
+            hashtable.insert(i, i.to_string());
+        }
+
+        hashtable.remove(&2);
+        hashtable.remove(&0);
+
+        for i in 0..6 {
+            if i == 2 || i == 0 {
+                assert_eq!(None, hashtable.get(&i));
+            } else {
+                assert_eq!(Some(&i.to_string()), hashtable.get(&i));
+            }
+        }
+        assert_eq!(4, hashtable.len());
+    }
+
+    #[test]
+    fn entry_counts_occurrences() {
+        let mut counts = HashTable::new();
+
+        for word in ["a", "b", "a", "c", "a", "b"] {
+            *counts.entry(word.to_string()).or_insert(0) += 1;
+        }
+
+        assert_eq!(Some(&3), counts.get(&"a".to_string()));
+        assert_eq!(Some(&2), counts.get(&"b".to_string()));
+        assert_eq!(Some(&1), counts.get(&"c".to_string()));
+    }
+
+    #[test]
+    fn entry_and_modify_leaves_vacant_untouched() {
+        let mut hashtable = HashTable::new();
+
+        hashtable.entry("x".to_string()).and_modify(|v| *v += 1).or_insert(10);
+        assert_eq!(Some(&10), hashtable.get(&"x".to_string()));
+
+        hashtable.entry("x".to_string()).and_modify(|v| *v += 1).or_insert(10);
+        assert_eq!(Some(&11), hashtable.get(&"x".to_string()));
+    }
+
+    #[test]
+    fn custom_hasher_is_used() {
+        use std::hash::BuildHasherDefault;
+        use std::collections::hash_map::DefaultHasher;
+
+        let mut hashtable: HashTable<&str, i32, BuildHasherDefault<DefaultHasher>> =
+            HashTable::with_hasher(BuildHasherDefault::default());
+
+        hashtable.insert("a", 1);
+        hashtable.insert("b", 2);
+
+        assert_eq!(Some(&1), hashtable.get(&"a"));
+        assert_eq!(Some(&2), hashtable.get(&"b"));
+    }
+
+    #[test]
+    fn iterates_over_all_entries() {
+        let mut hashtable = HashTable::new();
+        hashtable.insert("a", 1);
+        hashtable.insert("b", 2);
+        hashtable.insert("c", 3);
+
+        let mut pairs: Vec<_> = hashtable.iter().map(|(k, v)| (*k, *v)).collect();
+        pairs.sort();
+        assert_eq!(vec![("a", 1), ("b", 2), ("c", 3)], pairs);
+        assert_eq!(3, hashtable.iter().len());
+
+        let mut keys: Vec<_> = hashtable.keys().copied().collect();
+        keys.sort();
+        assert_eq!(vec!["a", "b", "c"], keys);
+
+        let mut values: Vec<_> = hashtable.values().copied().collect();
+        values.sort();
+        assert_eq!(vec![1, 2, 3], values);
+
+        for value in hashtable.values_mut() {
+            *value *= 10;
+        }
+        let mut scaled: Vec<_> = hashtable.values().copied().collect();
+        scaled.sort();
+        assert_eq!(vec![10, 20, 30], scaled);
+    }
+
+    #[test]
+    fn supports_for_loop_collect_and_extend() {
+        let mut hashtable = HashTable::new();
+        hashtable.insert(1, "one");
+        hashtable.insert(2, "two");
+
+        let mut seen = Vec::new();
+        for (k, v) in &hashtable {
+            seen.push((*k, *v));
+        }
+        seen.sort();
+        assert_eq!(vec![(1, "one"), (2, "two")], seen);
+
+        hashtable.extend([(3, "three"), (4, "four")]);
+        assert_eq!(4, hashtable.len());
+        assert_eq!(Some(&"three"), hashtable.get(&3));
+
+        let collected: HashTable<i32, &str> =
+            [(1, "one"), (2, "two")].into_iter().collect();
+        assert_eq!(Some(&"one"), collected.get(&1));
+        assert_eq!(Some(&"two"), collected.get(&2));
+
+        let mut owned: Vec<_> = hashtable.into_iter().collect();
+        owned.sort();
+        assert_eq!(vec![(1, "one"), (2, "two"), (3, "three"), (4, "four")], owned);
+    }
+
+    #[test]
+    fn reserve_grows_capacity_up_front() {
+        let mut hashtable = HashTable::with_capacity(4);
+        let capacity_before = hashtable.capacity();
+
+        hashtable.reserve(100);
+        assert!(hashtable.capacity() > capacity_before);
+        assert!(hashtable.capacity() as f64 * 0.75 >= 100.0);
+
+        let capacity_after_reserve = hashtable.capacity();
+        for i in 0..100 {
+            hashtable.insert(i, i);
+        }
+        assert_eq!(capacity_after_reserve, hashtable.capacity());
+    }
+
+    #[test]
+    fn try_reserve_reports_capacity_overflow() {
+        let mut hashtable: HashTable<i32, i32> = HashTable::new();
+        assert_eq!(Err(TryReserveError::CapacityOverflow), hashtable.try_reserve(usize::MAX));
+    }
 }